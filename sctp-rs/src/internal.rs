@@ -96,22 +96,50 @@ pub(crate) fn sctp_peeloff_internal(
     }
 }
 
+/// Flags OR'd into the `type` argument of `socket(2)`, following the same
+/// pattern as nix's `SockFlag`: requesting them here creates the socket
+/// non-blocking and/or close-on-exec atomically, instead of needing a
+/// follow-up `fcntl` call that leaves an fd-leak window across `exec`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SctpSocketFlags {
+    pub non_blocking: bool,
+    pub close_on_exec: bool,
+}
+
+impl SctpSocketFlags {
+    fn as_type_bits(&self) -> libc::c_int {
+        let mut bits = 0;
+        if self.non_blocking {
+            bits |= libc::SOCK_NONBLOCK;
+        }
+        if self.close_on_exec {
+            bits |= libc::SOCK_CLOEXEC;
+        }
+        bits
+    }
+}
+
 /// Implementation of `socket` using `libc::socket`.
 ///
 /// Based on the type of the requested socket, we pass different `type` parameter to actual
-/// `libc::socket` call. See section 3.1.1 and section 4.1.1 of RFC 6458.
+/// `libc::socket` call. See section 3.1.1 and section 4.1.1 of RFC 6458. `flags` is OR'd into
+/// the `type` argument so callers can atomically request a non-blocking and/or CLOEXEC socket.
 pub(crate) fn sctp_socket_internal(
     domain: libc::c_int,
     assoc: crate::SocketToAssociation,
+    flags: SctpSocketFlags,
 ) -> RawFd {
+    let extra_bits = flags.as_type_bits();
     unsafe {
         match assoc {
             crate::SocketToAssociation::OneToOne => {
-                libc::socket(domain, libc::SOCK_STREAM, libc::IPPROTO_SCTP)
-            }
-            crate::SocketToAssociation::OneToMany => {
-                libc::socket(domain, libc::SOCK_SEQPACKET, libc::IPPROTO_SCTP)
+                libc::socket(domain, libc::SOCK_STREAM | extra_bits, libc::IPPROTO_SCTP)
             }
+            crate::SocketToAssociation::OneToMany => libc::socket(
+                domain,
+                libc::SOCK_SEQPACKET | extra_bits,
+                libc::IPPROTO_SCTP,
+            ),
         }
     }
 }
@@ -311,27 +339,258 @@ pub(crate) fn shutdown_internal(fd: RawFd, how: std::net::Shutdown) -> std::io::
     }
 }
 
+// Wire layout of `struct sctp_sndrcvinfo` from `<netinet/sctp.h>`, carried as
+// an `SCTP_SNDRCV` ancillary control message on both the send and receive
+// paths.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawSndRcvInfo {
+    pub(crate) sinfo_stream: u16,
+    pub(crate) sinfo_ssn: u16,
+    pub(crate) sinfo_flags: u16,
+    pub(crate) sinfo_pr_policy: u16,
+    pub(crate) sinfo_ppid: u32,
+    pub(crate) sinfo_context: u32,
+    pub(crate) sinfo_timetolive: u32,
+    pub(crate) sinfo_tsn: u32,
+    pub(crate) sinfo_cumtsn: u32,
+    pub(crate) sinfo_assoc_id: SctpAssociationId,
+}
+
+/// Per-message ancillary send information, delivered to the kernel as an
+/// `SCTP_SNDRCV` control message alongside the data. Lets a caller target a
+/// specific outbound stream and, on a one-to-many socket, a specific
+/// association.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SctpSendData {
+    /// Stream number to send the message on.
+    pub stream: u16,
+    /// Payload protocol identifier delivered to the peer.
+    pub ppid: u32,
+    /// Opaque context value echoed back on an `SCTP_SEND_FAILED` notification.
+    pub context: u32,
+    /// Association to send to. Ignored on one-to-one sockets.
+    pub assoc_id: SctpAssociationId,
+    /// `SCTP_UNORDERED` / `SCTP_EOF` / `SCTP_ABORT` etc, OR'd together.
+    pub flags: u16,
+}
+
+impl From<SctpSendData> for RawSndRcvInfo {
+    fn from(send_data: SctpSendData) -> Self {
+        RawSndRcvInfo {
+            sinfo_stream: send_data.stream,
+            sinfo_ppid: send_data.ppid,
+            sinfo_context: send_data.context,
+            sinfo_assoc_id: send_data.assoc_id,
+            sinfo_flags: send_data.flags,
+            ..Default::default()
+        }
+    }
+}
+
+// Implementation for the send side for SCTP. Mirrors `sctp_recvmsg_internal`:
+// the message data goes in the `iovec`, and, when `send_data` is provided, an
+// `SCTP_SNDRCV` control message built with the `CMSG_*` helpers carries the
+// stream/PPID/flags routing information.
+pub(crate) fn sctp_sendmsg_internal(
+    fd: RawFd,
+    data: &[u8],
+    send_data: Option<SctpSendData>,
+) -> std::io::Result<usize> {
+    let mut send_iov = libc::iovec {
+        iov_base: data.as_ptr() as *mut _ as *mut libc::c_void,
+        iov_len: data.len(),
+    };
+
+    let mut sendmsg_header = libc::msghdr {
+        msg_name: std::ptr::null_mut(),
+        msg_namelen: 0,
+        msg_iov: &mut send_iov,
+        msg_iovlen: 1,
+        msg_control: std::ptr::null::<libc::c_int>() as *mut libc::c_void,
+        msg_controllen: 0,
+        msg_flags: 0,
+    };
+
+    // Kept alive until after `sendmsg` returns: `msg_control` points into it.
+    let mut cmsg_buffer: Vec<u8>;
+
+    // Safety: `cmsg_buffer` is sized using `CMSG_SPACE` so the `cmsghdr` and
+    // the `sctp_sndrcvinfo` payload both fit, and it outlives the `sendmsg`
+    // call below since it isn't dropped until the end of the function.
+    unsafe {
+        if let Some(send_data) = send_data {
+            let cmsg_space = libc::CMSG_SPACE(std::mem::size_of::<RawSndRcvInfo>() as u32) as usize;
+            cmsg_buffer = vec![0_u8; cmsg_space];
+
+            sendmsg_header.msg_control = cmsg_buffer.as_mut_ptr() as *mut libc::c_void;
+            sendmsg_header.msg_controllen = cmsg_space as _;
+
+            let cmsg = libc::CMSG_FIRSTHDR(&sendmsg_header);
+            (*cmsg).cmsg_level = SOL_SCTP;
+            (*cmsg).cmsg_type = SCTP_SNDRCV;
+            (*cmsg).cmsg_len = libc::CMSG_LEN(std::mem::size_of::<RawSndRcvInfo>() as u32) as _;
+
+            let raw_info: RawSndRcvInfo = send_data.into();
+            std::ptr::copy_nonoverlapping(
+                &raw_info as *const _ as *const u8,
+                libc::CMSG_DATA(cmsg),
+                std::mem::size_of::<RawSndRcvInfo>(),
+            );
+        }
+
+        let result = libc::sendmsg(fd, &sendmsg_header, 0);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(result as usize)
+        }
+    }
+}
+
+/// Per-message ancillary data delivered alongside a data read when
+/// `SctpEvent::DataIo` is subscribed: the `SCTP_SNDRCV` control message
+/// parsed out of the `recvmsg` ancillary data. Carries the stream, stream
+/// sequence number, PPID, TSN/cumulative TSN and the association the data
+/// belongs to.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SndRcvInfo {
+    pub stream: u16,
+    pub ssn: u16,
+    pub flags: u16,
+    pub ppid: u32,
+    pub context: u32,
+    pub tsn: u32,
+    pub cumtsn: u32,
+    pub assoc_id: SctpAssociationId,
+}
+
+impl From<RawSndRcvInfo> for SndRcvInfo {
+    fn from(raw: RawSndRcvInfo) -> Self {
+        SndRcvInfo {
+            stream: raw.sinfo_stream,
+            ssn: raw.sinfo_ssn,
+            flags: raw.sinfo_flags,
+            ppid: raw.sinfo_ppid,
+            context: raw.sinfo_context,
+            tsn: raw.sinfo_tsn,
+            cumtsn: raw.sinfo_cumtsn,
+            assoc_id: raw.sinfo_assoc_id,
+        }
+    }
+}
+
+// Wire layout of the newer, smaller `struct sctp_sndinfo` from
+// `<netinet/sctp.h>`, used by `SCTP_SEND_FAILED_EVENT` in place of the full
+// `sctp_sndrcvinfo` the older `SCTP_SEND_FAILED` carries.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawSndInfo {
+    pub(crate) snd_sid: u16,
+    pub(crate) snd_flags: u16,
+    pub(crate) snd_ppid: u32,
+    pub(crate) snd_context: u32,
+    pub(crate) snd_assoc_id: SctpAssociationId,
+}
+
+impl From<RawSndInfo> for SndRcvInfo {
+    fn from(raw: RawSndInfo) -> Self {
+        SndRcvInfo {
+            stream: raw.snd_sid,
+            flags: raw.snd_flags,
+            ppid: raw.snd_ppid,
+            context: raw.snd_context,
+            assoc_id: raw.snd_assoc_id,
+            ..Default::default()
+        }
+    }
+}
+
+/// Default size of the per-`recvmsg` chunk buffer used while reassembling a
+/// partially delivered message, unless the caller overrides it on the
+/// socket.
+pub(crate) const DEFAULT_RECV_BUFFER_SIZE: usize = 4096;
+
 // Implementation for the receive side for SCTP.
-// TODO: Handle Control Message Header
-pub(crate) fn sctp_recvmsg_internal(fd: RawFd) -> std::io::Result<SctpNotificationOrData> {
-    let mut recv_buffer = vec![0_u8; 4096];
+//
+// A single `recvmsg` call is not guaranteed to return a complete message:
+// with the partial delivery API, large messages are split across several
+// reads and only the last one has `MSG_EOR` set in `msg_flags`. Loop,
+// appending each chunk, until `MSG_EOR` is seen. Notifications always arrive
+// with `MSG_EOR` set, so they short-circuit the loop on the first read.
+//
+// Requires a blocking `fd` for the duration of a partial delivery: on a
+// non-blocking socket, a `WouldBlock` from a later call in the loop returns
+// an error and drops whatever has already been reassembled, since there is
+// nowhere in this function to stash partial state between calls. Callers
+// driving reassembly from a non-blocking/mio socket must not call this for a
+// message already in flight without being prepared to lose the partial read;
+// a future revision should move the partial-delivery buffer onto the socket
+// type itself so it can survive a `WouldBlock` across calls.
+pub(crate) fn sctp_recvmsg_internal(
+    fd: RawFd,
+    recv_buffer_size: usize,
+) -> std::io::Result<SctpNotificationOrData> {
+    let mut reassembled: Vec<u8> = Vec::with_capacity(recv_buffer_size);
+    let mut sndrcvinfo: Option<SndRcvInfo> = None;
+
+    loop {
+        let (chunk, received_flags, chunk_sndrcvinfo) = sctp_recvmsg_once(fd, recv_buffer_size)?;
+
+        if received_flags & MSG_NOTIFICATION != 0 {
+            return Ok(SctpNotificationOrData::Notification(
+                notification_from_message(&chunk),
+            ));
+        }
+
+        // An orderly shutdown surfaces as a 0-byte read with no `MSG_EOR`.
+        // Without this, a peer that shuts down mid-message would make this
+        // loop spin forever re-issuing 0-byte `recvmsg` calls.
+        if chunk.is_empty() && received_flags & (libc::MSG_EOR as u32) == 0 {
+            break;
+        }
+
+        reassembled.extend_from_slice(&chunk);
+        if sndrcvinfo.is_none() {
+            sndrcvinfo = chunk_sndrcvinfo;
+        }
+
+        if received_flags & (libc::MSG_EOR as u32) != 0 {
+            break;
+        }
+    }
+
+    Ok(SctpNotificationOrData::Data(reassembled, sndrcvinfo))
+}
+
+// A single `recvmsg` call, reading at most `recv_buffer_size` bytes into a
+// fresh buffer. Returns the data actually read, the kernel's `msg_flags`,
+// and the `SCTP_SNDRCV` ancillary data if any was attached.
+fn sctp_recvmsg_once(
+    fd: RawFd,
+    recv_buffer_size: usize,
+) -> std::io::Result<(Vec<u8>, u32, Option<SndRcvInfo>)> {
+    let mut recv_buffer = vec![0_u8; recv_buffer_size];
     let mut recv_iov = libc::iovec {
         iov_base: recv_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
         iov_len: recv_buffer.len(),
     };
 
     let mut from_buffer = vec![0u8; 256];
+    let cmsg_space = unsafe { libc::CMSG_SPACE(std::mem::size_of::<RawSndRcvInfo>() as u32) };
+    let mut cmsg_buffer = vec![0_u8; cmsg_space as usize];
     let mut recvmsg_header = libc::msghdr {
         msg_name: from_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
         msg_namelen: from_buffer.len() as u32,
         msg_iov: &mut recv_iov,
         msg_iovlen: 1,
-        msg_control: std::ptr::null::<libc::c_int>() as *mut libc::c_void,
-        msg_controllen: 0,
+        msg_control: cmsg_buffer.as_mut_ptr() as *mut libc::c_void,
+        msg_controllen: cmsg_buffer.len() as _,
         msg_flags: 0,
     };
 
-    // Safety: recvmsg_hdr is valid in the current scope.
+    // Safety: recvmsg_hdr, and the buffers it points into, are valid in the
+    // current scope.
     unsafe {
         let flags = 0 as libc::c_int;
         let result = libc::recvmsg(fd, &mut recvmsg_header as *mut libc::msghdr, flags);
@@ -343,25 +602,157 @@ pub(crate) fn sctp_recvmsg_internal(fd: RawFd) -> std::io::Result<SctpNotificati
             recv_buffer.truncate(result as usize);
             eprintln!("buffer: {:x?}", recv_buffer);
 
-            if received_flags & MSG_NOTIFICATION != 0 {
-                Ok(SctpNotificationOrData::Notification(
-                    notification_from_message(&recv_buffer),
-                ))
-            } else {
-                Ok(SctpNotificationOrData::Data(recv_buffer))
+            if received_flags & (libc::MSG_CTRUNC as u32) != 0 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "ancillary control message data was truncated",
+                ));
             }
+
+            let sndrcvinfo = sndrcvinfo_from_cmsg(&recvmsg_header);
+            Ok((recv_buffer, received_flags, sndrcvinfo))
+        }
+    }
+}
+
+// Walk the ancillary data looking for an `SCTP_SNDRCV` control message and,
+// if found, copy the `sctp_sndrcvinfo` payload out of `CMSG_DATA`.
+//
+// Safety: `header` must be the `msghdr` just populated by a successful call
+// to `recvmsg`, so its `msg_control` buffer is valid for `msg_controllen`
+// bytes.
+unsafe fn sndrcvinfo_from_cmsg(header: &libc::msghdr) -> Option<SndRcvInfo> {
+    let mut cmsg = libc::CMSG_FIRSTHDR(header);
+    while !cmsg.is_null() {
+        if (*cmsg).cmsg_level == SOL_SCTP && (*cmsg).cmsg_type == SCTP_SNDRCV {
+            let mut raw = RawSndRcvInfo::default();
+            std::ptr::copy_nonoverlapping(
+                libc::CMSG_DATA(cmsg),
+                &mut raw as *mut _ as *mut u8,
+                std::mem::size_of::<RawSndRcvInfo>(),
+            );
+            return Some(raw.into());
         }
+        cmsg = libc::CMSG_NXTHDR(header, cmsg);
     }
+    None
+}
+
+/// A peer address transitioned between reachable/unreachable, or was added
+/// or removed, as delivered in an `SCTP_PEER_ADDR_CHANGE` notification.
+#[derive(Clone, Debug)]
+pub struct PeerAddrChange {
+    pub addr_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub address: SocketAddr,
+    pub state: i32,
+    pub error: i32,
+    pub assoc_id: SctpAssociationId,
+}
+
+/// A message could not be sent, as delivered in an `SCTP_SEND_FAILED` (or the
+/// newer `SCTP_SEND_FAILED_EVENT`) notification.
+#[derive(Clone, Debug)]
+pub struct SendFailed {
+    pub send_failed_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub error: u32,
+    pub info: SndRcvInfo,
+    pub assoc_id: SctpAssociationId,
+    pub data: Vec<u8>,
+}
+
+/// An `SCTP_REMOTE_ERROR` notification: the peer sent us an Operation Error
+/// chunk.
+#[derive(Clone, Debug)]
+pub struct RemoteError {
+    pub remote_error_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub error: u16,
+    pub assoc_id: SctpAssociationId,
+    pub data: Vec<u8>,
+}
+
+/// An `SCTP_SHUTDOWN_EVENT` notification: the peer has shut down its side of
+/// the association.
+#[derive(Clone, Copy, Debug)]
+pub struct ShutdownEvent {
+    pub shutdown_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub assoc_id: SctpAssociationId,
+}
+
+/// An `SCTP_PARTIAL_DELIVERY_EVENT` notification.
+#[derive(Clone, Copy, Debug)]
+pub struct PartialDeliveryEvent {
+    pub pdapi_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub indication: u32,
+    pub assoc_id: SctpAssociationId,
+}
+
+/// An `SCTP_ADAPTATION_INDICATION` notification carrying the peer's
+/// adaptation layer indication.
+#[derive(Clone, Copy, Debug)]
+pub struct AdaptationIndication {
+    pub adaptation_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub adaptation_ind: u32,
+    pub assoc_id: SctpAssociationId,
+}
+
+/// An `SCTP_SENDER_DRY_EVENT` notification: there is no more outstanding
+/// unsent/unacknowledged data on the association.
+#[derive(Clone, Copy, Debug)]
+pub struct SenderDryEvent {
+    pub sender_dry_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub assoc_id: SctpAssociationId,
+}
+
+/// An `SCTP_STREAM_RESET_EVENT` notification, naming the streams that were
+/// reset.
+#[derive(Clone, Debug)]
+pub struct StreamResetEvent {
+    pub stream_reset_type: u16,
+    pub flags: u16,
+    pub length: u32,
+    pub assoc_id: SctpAssociationId,
+    pub streams: Vec<u16>,
 }
 
 fn notification_from_message(data: &[u8]) -> SctpNotification {
+    // Every notification starts with `type`/`flags`/`length` - bail out to
+    // `Unsupported` rather than panicking on a short or corrupt buffer. This
+    // has to come before any indexing into `data`, including the `type` read
+    // just below.
+    if data.len() < 8 {
+        return SctpNotification::Unsupported;
+    }
+
     let notification_type = u16::from_ne_bytes(data[0..2].try_into().unwrap());
     eprintln!(
         "notification_type: {:x}, SCTP_ASSOC_CHANGE: {:x}",
         notification_type, SCTP_ASSOC_CHANGE
     );
+
+    let length = u32::from_ne_bytes(data[4..8].try_into().unwrap()) as usize;
+    if length > data.len() {
+        return SctpNotification::Unsupported;
+    }
+
     match notification_type {
         SCTP_ASSOC_CHANGE => {
+            if length < 20 {
+                return SctpNotification::Unsupported;
+            }
             let assoc_change = AssociationChange {
                 assoc_type: u16::from_ne_bytes(data[0..2].try_into().unwrap()),
                 flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
@@ -371,10 +762,181 @@ fn notification_from_message(data: &[u8]) -> SctpNotification {
                 ob_streams: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
                 ib_streams: u16::from_ne_bytes(data[14..16].try_into().unwrap()),
                 assoc_id: i32::from_ne_bytes(data[16..20].try_into().unwrap()),
-                info: data[20..].into(),
+                info: data[20..length].into(),
             };
             SctpNotification::AssociationChange(assoc_change)
         }
+        SCTP_PEER_ADDR_CHANGE => {
+            // `spc_aaddr` is a `sockaddr_storage` (128 bytes), followed by
+            // `spc_state`, `spc_error` and `spc_assoc_id` (4 bytes each).
+            if length < 8 + 128 + 12 {
+                return SctpNotification::Unsupported;
+            }
+            let os_socketaddr = OsSocketAddr::from_raw_parts(data[8..8 + 128].as_ptr(), 128);
+            let address = match os_socketaddr.into_addr() {
+                Some(address) => address,
+                None => return SctpNotification::Unsupported,
+            };
+            let peer_addr_change = PeerAddrChange {
+                addr_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                address,
+                state: i32::from_ne_bytes(data[136..140].try_into().unwrap()),
+                error: i32::from_ne_bytes(data[140..144].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[144..148].try_into().unwrap()),
+            };
+            SctpNotification::PeerAddrChange(peer_addr_change)
+        }
+        SCTP_SEND_FAILED => {
+            // `struct sctp_send_failed`: `ssf_info` is the full 32-byte
+            // `sctp_sndrcvinfo`, followed by `ssf_assoc_id` and `ssf_data[]`.
+            let info_offset = 12;
+            let info_size = std::mem::size_of::<RawSndRcvInfo>();
+            let assoc_id_offset = info_offset + info_size;
+            if length < assoc_id_offset + 4 {
+                return SctpNotification::Unsupported;
+            }
+            let mut raw_info = RawSndRcvInfo::default();
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    data[info_offset..info_offset + info_size].as_ptr(),
+                    &mut raw_info as *mut _ as *mut u8,
+                    info_size,
+                );
+            }
+            let send_failed = SendFailed {
+                send_failed_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                error: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                info: raw_info.into(),
+                assoc_id: i32::from_ne_bytes(
+                    data[assoc_id_offset..assoc_id_offset + 4]
+                        .try_into()
+                        .unwrap(),
+                ),
+                data: data[assoc_id_offset + 4..length].into(),
+            };
+            SctpNotification::SendFailed(send_failed)
+        }
+        SCTP_SEND_FAILED_EVENT => {
+            // `struct sctp_send_failed_event`: `ssfe_info` is the newer,
+            // 16-byte `sctp_sndinfo` (no ssn/tsn/cumtsn), followed by
+            // `ssf_assoc_id` and `ssf_data[]`.
+            let info_offset = 12;
+            let info_size = std::mem::size_of::<RawSndInfo>();
+            let assoc_id_offset = info_offset + info_size;
+            if length < assoc_id_offset + 4 {
+                return SctpNotification::Unsupported;
+            }
+            let mut raw_info = RawSndInfo::default();
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    data[info_offset..info_offset + info_size].as_ptr(),
+                    &mut raw_info as *mut _ as *mut u8,
+                    info_size,
+                );
+            }
+            let send_failed = SendFailed {
+                send_failed_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                error: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                info: raw_info.into(),
+                assoc_id: i32::from_ne_bytes(
+                    data[assoc_id_offset..assoc_id_offset + 4]
+                        .try_into()
+                        .unwrap(),
+                ),
+                data: data[assoc_id_offset + 4..length].into(),
+            };
+            SctpNotification::SendFailed(send_failed)
+        }
+        SCTP_REMOTE_ERROR => {
+            // `struct sctp_remote_error`: `sre_error` (u16) at offset 8 is
+            // followed by 2 padding bytes before the 4-byte `sctp_assoc_t`,
+            // so `sre_assoc_id` lands at 12 and `sre_data[]` at 16.
+            if length < 16 {
+                return SctpNotification::Unsupported;
+            }
+            let remote_error = RemoteError {
+                remote_error_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                error: u16::from_ne_bytes(data[8..10].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[12..16].try_into().unwrap()),
+                data: data[16..length].into(),
+            };
+            SctpNotification::RemoteError(remote_error)
+        }
+        SCTP_SHUTDOWN_EVENT => {
+            if length < 12 {
+                return SctpNotification::Unsupported;
+            }
+            let shutdown_event = ShutdownEvent {
+                shutdown_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()),
+            };
+            SctpNotification::ShutdownEvent(shutdown_event)
+        }
+        SCTP_PARTIAL_DELIVERY_EVENT => {
+            if length < 16 {
+                return SctpNotification::Unsupported;
+            }
+            let partial_delivery_event = PartialDeliveryEvent {
+                pdapi_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                indication: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[12..16].try_into().unwrap()),
+            };
+            SctpNotification::PartialDeliveryEvent(partial_delivery_event)
+        }
+        SCTP_ADAPTATION_INDICATION => {
+            if length < 16 {
+                return SctpNotification::Unsupported;
+            }
+            let adaptation_indication = AdaptationIndication {
+                adaptation_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                adaptation_ind: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[12..16].try_into().unwrap()),
+            };
+            SctpNotification::AdaptationIndication(adaptation_indication)
+        }
+        SCTP_SENDER_DRY_EVENT => {
+            if length < 12 {
+                return SctpNotification::Unsupported;
+            }
+            let sender_dry_event = SenderDryEvent {
+                sender_dry_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()),
+            };
+            SctpNotification::SenderDryEvent(sender_dry_event)
+        }
+        SCTP_STREAM_RESET_EVENT => {
+            if length < 12 {
+                return SctpNotification::Unsupported;
+            }
+            let streams = data[12..length]
+                .chunks_exact(2)
+                .map(|chunk| u16::from_ne_bytes(chunk.try_into().unwrap()))
+                .collect();
+            let stream_reset_event = StreamResetEvent {
+                stream_reset_type: notification_type,
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: length as u32,
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                streams,
+            };
+            SctpNotification::StreamResetEvent(stream_reset_event)
+        }
         _ => SctpNotification::Unsupported,
     }
 }
@@ -425,6 +987,269 @@ pub(crate) fn sctp_events_subscribe_internal(
     }
 }
 
+// A typed SCTP socket option, following the same shape as nix's `sockopt`
+// module: each concrete type pairs a Rust-level value with the raw struct
+// `getsockopt`/`setsockopt` actually exchange with the kernel under
+// `SOL_SCTP`, so the unsafe call and layout live in one place per option.
+pub(crate) trait SctpSockOpt: Sized {
+    /// The raw struct exchanged with the kernel for this option.
+    type Wire: Copy;
+
+    /// The `SCTP_*` option name passed to `getsockopt`/`setsockopt`.
+    const OPTION: libc::c_int;
+
+    fn into_wire(self) -> Self::Wire;
+    /// Fallible so options whose wire value may not decode cleanly (e.g. a
+    /// `sockaddr_storage` in an address family `OsSocketAddr` can't handle)
+    /// can report an error instead of panicking on kernel-returned data.
+    fn from_wire(wire: Self::Wire) -> std::io::Result<Self>;
+}
+
+// Get a socket option. `query` is converted to the wire type and passed to
+// `getsockopt` as the initial value, which matters for association-scoped
+// options: the kernel expects the `assoc_id` field pre-filled on the way in.
+pub(crate) fn sctp_getsockopt<O: SctpSockOpt>(fd: RawFd, query: O) -> std::io::Result<O> {
+    let mut wire = query.into_wire();
+    let mut len = std::mem::size_of::<O::Wire>() as libc::socklen_t;
+
+    // Safety: `wire` and `len` are valid pointers for the duration of the call.
+    unsafe {
+        let result = libc::getsockopt(
+            fd,
+            SOL_SCTP,
+            O::OPTION,
+            &mut wire as *mut _ as *mut libc::c_void,
+            &mut len as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            O::from_wire(wire)
+        }
+    }
+}
+
+// Set a socket option.
+pub(crate) fn sctp_setsockopt<O: SctpSockOpt>(fd: RawFd, opt: O) -> std::io::Result<()> {
+    let wire = opt.into_wire();
+
+    // Safety: `wire` is a valid pointer for the duration of the call.
+    unsafe {
+        let result = libc::setsockopt(
+            fd,
+            SOL_SCTP,
+            O::OPTION,
+            &wire as *const _ as *const libc::c_void,
+            std::mem::size_of::<O::Wire>() as libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// `SCTP_RTOINFO`: the association's retransmission timeout bounds, in
+/// milliseconds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RtoInfo {
+    pub assoc_id: SctpAssociationId,
+    pub initial: u32,
+    pub max: u32,
+    pub min: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawRtoInfo {
+    srto_assoc_id: SctpAssociationId,
+    srto_initial: u32,
+    srto_max: u32,
+    srto_min: u32,
+}
+
+impl SctpSockOpt for RtoInfo {
+    type Wire = RawRtoInfo;
+    const OPTION: libc::c_int = SCTP_RTOINFO;
+
+    fn into_wire(self) -> RawRtoInfo {
+        RawRtoInfo {
+            srto_assoc_id: self.assoc_id,
+            srto_initial: self.initial,
+            srto_max: self.max,
+            srto_min: self.min,
+        }
+    }
+
+    fn from_wire(wire: RawRtoInfo) -> std::io::Result<Self> {
+        Ok(RtoInfo {
+            assoc_id: wire.srto_assoc_id,
+            initial: wire.srto_initial,
+            max: wire.srto_max,
+            min: wire.srto_min,
+        })
+    }
+}
+
+/// `SCTP_ASSOCINFO`: per-association tunables - max retransmissions before
+/// the association is considered unreachable, the number of destination
+/// addresses, the peer/local receive windows, and the cookie lifespan.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AssocInfo {
+    pub assoc_id: SctpAssociationId,
+    pub max_retrans: u16,
+    pub number_peer_destinations: u16,
+    pub peer_rwnd: u32,
+    pub local_rwnd: u32,
+    pub cookie_life: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawAssocInfo {
+    sasoc_assoc_id: SctpAssociationId,
+    sasoc_asocmaxrxt: u16,
+    sasoc_number_peer_destinations: u16,
+    sasoc_peer_rwnd: u32,
+    sasoc_local_rwnd: u32,
+    sasoc_cookie_life: u32,
+}
+
+impl SctpSockOpt for AssocInfo {
+    type Wire = RawAssocInfo;
+    const OPTION: libc::c_int = SCTP_ASSOCINFO;
+
+    fn into_wire(self) -> RawAssocInfo {
+        RawAssocInfo {
+            sasoc_assoc_id: self.assoc_id,
+            sasoc_asocmaxrxt: self.max_retrans,
+            sasoc_number_peer_destinations: self.number_peer_destinations,
+            sasoc_peer_rwnd: self.peer_rwnd,
+            sasoc_local_rwnd: self.local_rwnd,
+            sasoc_cookie_life: self.cookie_life,
+        }
+    }
+
+    fn from_wire(wire: RawAssocInfo) -> std::io::Result<Self> {
+        Ok(AssocInfo {
+            assoc_id: wire.sasoc_assoc_id,
+            max_retrans: wire.sasoc_asocmaxrxt,
+            number_peer_destinations: wire.sasoc_number_peer_destinations,
+            peer_rwnd: wire.sasoc_peer_rwnd,
+            local_rwnd: wire.sasoc_local_rwnd,
+            cookie_life: wire.sasoc_cookie_life,
+        })
+    }
+}
+
+/// `SCTP_INITMSG`: parameters used for the `INIT` sent on the next
+/// association attempt - requested outbound streams, the cap on inbound
+/// streams, the max number of retransmissions, and the max init timeout.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InitMsg {
+    pub num_ostreams: u16,
+    pub max_instreams: u16,
+    pub max_attempts: u16,
+    pub max_init_timeo: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RawInitMsg {
+    sinit_num_ostreams: u16,
+    sinit_max_instreams: u16,
+    sinit_max_attempts: u16,
+    sinit_max_init_timeo: u16,
+}
+
+impl SctpSockOpt for InitMsg {
+    type Wire = RawInitMsg;
+    const OPTION: libc::c_int = SCTP_INITMSG;
+
+    fn into_wire(self) -> RawInitMsg {
+        RawInitMsg {
+            sinit_num_ostreams: self.num_ostreams,
+            sinit_max_instreams: self.max_instreams,
+            sinit_max_attempts: self.max_attempts,
+            sinit_max_init_timeo: self.max_init_timeo,
+        }
+    }
+
+    fn from_wire(wire: RawInitMsg) -> std::io::Result<Self> {
+        Ok(InitMsg {
+            num_ostreams: wire.sinit_num_ostreams,
+            max_instreams: wire.sinit_max_instreams,
+            max_attempts: wire.sinit_max_attempts,
+            max_init_timeo: wire.sinit_max_init_timeo,
+        })
+    }
+}
+
+/// `SCTP_NODELAY`: disables Nagle-style coalescing of small outbound
+/// messages.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoDelay(pub bool);
+
+impl SctpSockOpt for NoDelay {
+    type Wire = libc::c_int;
+    const OPTION: libc::c_int = SCTP_NODELAY;
+
+    fn into_wire(self) -> libc::c_int {
+        self.0 as libc::c_int
+    }
+
+    fn from_wire(wire: libc::c_int) -> std::io::Result<Self> {
+        Ok(NoDelay(wire != 0))
+    }
+}
+
+/// `SCTP_PRIMARY_ADDR`: the association's primary destination address, used
+/// as the default for outbound data.
+#[derive(Clone, Copy, Debug)]
+pub struct PrimaryAddr {
+    pub assoc_id: SctpAssociationId,
+    pub address: SocketAddr,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct RawPrimaryAddr {
+    ssp_assoc_id: SctpAssociationId,
+    ssp_addr: [u8; 128],
+}
+
+impl SctpSockOpt for PrimaryAddr {
+    type Wire = RawPrimaryAddr;
+    const OPTION: libc::c_int = SCTP_PRIMARY_ADDR;
+
+    fn into_wire(self) -> RawPrimaryAddr {
+        let mut ssp_addr = [0_u8; 128];
+        let ossockaddr: OsSocketAddr = self.address.into();
+        let slice = ossockaddr.as_ref();
+        ssp_addr[..slice.len()].copy_from_slice(slice);
+        RawPrimaryAddr {
+            ssp_assoc_id: self.assoc_id,
+            ssp_addr,
+        }
+    }
+
+    fn from_wire(wire: RawPrimaryAddr) -> std::io::Result<Self> {
+        let os_socketaddr =
+            OsSocketAddr::from_raw_parts(wire.ssp_addr.as_ptr(), wire.ssp_addr.len());
+        let address = os_socketaddr.into_addr().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "SCTP_PRIMARY_ADDR returned an unsupported address family",
+            )
+        })?;
+        Ok(PrimaryAddr {
+            assoc_id: wire.ssp_assoc_id,
+            address,
+        })
+    }
+}
+
 // Close the socket
 #[inline(always)]
 pub(crate) fn close_internal(fd: RawFd) {
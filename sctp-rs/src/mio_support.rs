@@ -0,0 +1,65 @@
+//! Optional `mio` integration for the SCTP socket types.
+//!
+//! Gated behind the `mio` feature. Combined with a non-blocking socket
+//! created via [`SctpSocketFlags`](crate::internal::SctpSocketFlags), this
+//! lets [`SctpListener`] and [`SctpConnectedSocket`] be registered with an
+//! epoll/kqueue-backed reactor and driven with `accept`/`sctp_recvmsg`/
+//! `sctp_sendmsg`, which already return `WouldBlock` (surfaced from `EAGAIN`
+//! via `last_os_error`) so the reactor knows when to re-arm interest.
+
+use std::io;
+use std::os::unix::io::AsRawFd;
+
+use mio::event::Source;
+use mio::unix::SourceFd;
+use mio::{Interest, Registry, Token};
+
+use crate::{SctpConnectedSocket, SctpListener};
+
+impl Source for SctpListener {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}
+
+impl Source for SctpConnectedSocket {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        SourceFd(&self.as_raw_fd()).deregister(registry)
+    }
+}